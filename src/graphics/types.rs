@@ -1,4 +1,6 @@
 use std::f32;
+use std::fmt;
+use std::str::FromStr;
 use std::u32;
 use nalgebra as na;
 
@@ -117,11 +119,53 @@ impl Rect {
     }
 
     /// Scales the `Rect` by a factor of (sx, sy),
-    /// growing towards the bottom-left
+    /// keeping its origin (top-left corner) fixed.
     pub fn scale(&mut self, sx: f32, sy: f32) {
         self.w *= sx;
         self.h *= sy;
     }
+
+    /// Returns the smallest `Rect` that contains both `self` and `other`.
+    pub fn combine_with(&self, other: &Rect) -> Rect {
+        let left = self.left().min(other.left());
+        let top = self.top().min(other.top());
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+        Rect::new(left, top, right - left, bottom - top)
+    }
+
+    /// Returns the overlapping region between `self` and `other`,
+    /// or `None` if they do not overlap.
+    ///
+    /// Agrees with `overlaps`: `Rect`s that only touch along an edge are
+    /// considered overlapping, and yield a zero-area `Rect` at the shared
+    /// boundary rather than `None`.
+    pub fn intersect(&self, other: &Rect) -> Option<Rect> {
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        if left > right || top > bottom {
+            None
+        } else {
+            Some(Rect::new(left, top, right - left, bottom - top))
+        }
+    }
+
+    /// Returns the axis-aligned bounding box of `points`,
+    /// or `None` if `points` is empty.
+    pub fn from_points(points: &[Point2]) -> Option<Rect> {
+        let mut points = points.iter();
+        let first = points.next()?;
+        let (mut left, mut top, mut right, mut bottom) = (first.x, first.y, first.x, first.y);
+        for p in points {
+            left = left.min(p.x);
+            top = top.min(p.y);
+            right = right.max(p.x);
+            bottom = bottom.max(p.y);
+        }
+        Some(Rect::new(left, top, right - left, bottom - top))
+    }
 }
 
 impl From<[f32; 4]> for Rect {
@@ -293,6 +337,393 @@ impl From<Color> for [f32; 4] {
     }
 }
 
+/// An error returned when a CSS-style color string could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorParseError(String);
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' is not a valid CSS color", self.0)
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+impl Color {
+    /// Parses a CSS-style color string.
+    ///
+    /// Accepts hex notation (`#RGB`, `#RGBA`, `#RRGGBB`, `#RRGGBBAA`),
+    /// `rgb()`/`rgba()` functional notation (channels as integers `0-255` or
+    /// percentages, alpha as a float `0.0-1.0`), the keyword `transparent`,
+    /// and the standard CSS3 named colors (eg `"rebeccapurple"`).
+    pub fn from_css(s: &str) -> Result<Color, ColorParseError> {
+        let trimmed = s.trim();
+        let err = || ColorParseError(s.to_owned());
+
+        if let Some(hex) = trimmed.strip_prefix('#') {
+            return Color::from_css_hex(hex).ok_or_else(err);
+        }
+
+        let lower = trimmed.to_ascii_lowercase();
+        if let Some(inner) = lower.strip_prefix("rgba(").and_then(|r| r.strip_suffix(')')) {
+            return Color::from_css_rgb(inner, true).ok_or_else(err);
+        }
+        if let Some(inner) = lower.strip_prefix("rgb(").and_then(|r| r.strip_suffix(')')) {
+            return Color::from_css_rgb(inner, false).ok_or_else(err);
+        }
+        if lower == "transparent" {
+            return Ok(Color::new(0.0, 0.0, 0.0, 0.0));
+        }
+        named_css_color(&lower)
+            .map(|(r, g, b)| Color::from_rgb(r, g, b))
+            .ok_or_else(err)
+    }
+
+    fn from_css_hex(hex: &str) -> Option<Color> {
+        fn nibble(c: u8) -> Option<u8> {
+            match c {
+                b'0'..=b'9' => Some(c - b'0'),
+                b'a'..=b'f' => Some(c - b'a' + 10),
+                b'A'..=b'F' => Some(c - b'A' + 10),
+                _ => None,
+            }
+        }
+        fn expand(n: u8) -> u8 {
+            (n << 4) | n
+        }
+        let nibbles: Option<Vec<u8>> = hex.bytes().map(nibble).collect();
+        let nibbles = nibbles?;
+        match nibbles.len() {
+            3 => Some(Color::from_rgb(
+                expand(nibbles[0]),
+                expand(nibbles[1]),
+                expand(nibbles[2]),
+            )),
+            4 => Some(Color::from_rgba(
+                expand(nibbles[0]),
+                expand(nibbles[1]),
+                expand(nibbles[2]),
+                expand(nibbles[3]),
+            )),
+            6 => Some(Color::from_rgb(
+                (nibbles[0] << 4) | nibbles[1],
+                (nibbles[2] << 4) | nibbles[3],
+                (nibbles[4] << 4) | nibbles[5],
+            )),
+            8 => Some(Color::from_rgba(
+                (nibbles[0] << 4) | nibbles[1],
+                (nibbles[2] << 4) | nibbles[3],
+                (nibbles[4] << 4) | nibbles[5],
+                (nibbles[6] << 4) | nibbles[7],
+            )),
+            _ => None,
+        }
+    }
+
+    fn from_css_rgb(inner: &str, has_alpha: bool) -> Option<Color> {
+        let parts: Vec<&str> = inner.split(',').map(|p| p.trim()).collect();
+        if parts.len() != if has_alpha { 4 } else { 3 } {
+            return None;
+        }
+        fn channel(s: &str) -> Option<u8> {
+            if let Some(pct) = s.strip_suffix('%') {
+                let v: f32 = pct.parse().ok()?;
+                Some((v.clamp(0.0, 100.0) / 100.0 * 255.0).round() as u8)
+            } else {
+                let v: f32 = s.parse().ok()?;
+                Some(v.clamp(0.0, 255.0).round() as u8)
+            }
+        }
+        let r = channel(parts[0])?;
+        let g = channel(parts[1])?;
+        let b = channel(parts[2])?;
+        let a = if has_alpha {
+            parts[3].parse::<f32>().ok()?.clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        Some(Color::from((r, g, b, (a * 255.0).round() as u8)))
+    }
+}
+
+impl Color {
+    /// Creates a new `Color` from HSV (hue in degrees, wrapping around `0.0..360.0`;
+    /// saturation, value and alpha in `0.0..=1.0`).
+    pub fn from_hsv(h: f32, s: f32, v: f32, a: f32) -> Color {
+        let c = v * s;
+        let (r, g, b) = hsx_to_rgb(h, c, v - c);
+        Color::new(r, g, b, a)
+    }
+
+    /// Converts this `Color` to HSV, returning `(hue, saturation, value, alpha)`.
+    pub fn to_hsv(self) -> (f32, f32, f32, f32) {
+        let (max, delta, hue) = rgb_to_hue(self.r, self.g, self.b);
+        let value = max;
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+        (hue, saturation, value, self.a)
+    }
+
+    /// Creates a new `Color` from HSL (hue in degrees, wrapping around `0.0..360.0`;
+    /// saturation, lightness and alpha in `0.0..=1.0`).
+    pub fn from_hsl(h: f32, s: f32, l: f32, a: f32) -> Color {
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let m = l - c / 2.0;
+        let (r, g, b) = hsx_to_rgb(h, c, m);
+        Color::new(r, g, b, a)
+    }
+
+    /// Converts this `Color` to HSL, returning `(hue, saturation, lightness, alpha)`.
+    pub fn to_hsl(self) -> (f32, f32, f32, f32) {
+        let (max, delta, hue) = rgb_to_hue(self.r, self.g, self.b);
+        let min = max - delta;
+        let lightness = (max + min) / 2.0;
+        let saturation = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        (hue, saturation, lightness, self.a)
+    }
+
+    /// Rotates this `Color`'s hue by `degrees`, preserving its HSL saturation and lightness.
+    pub fn rotate_hue(self, degrees: f32) -> Color {
+        let (h, s, l, a) = self.to_hsl();
+        Color::from_hsl(h + degrees, s, l, a)
+    }
+
+    /// Lightens this `Color` by `amount` (`0.0..=1.0`) in HSL space, clamped to fully light.
+    pub fn lighten(self, amount: f32) -> Color {
+        let (h, s, l, a) = self.to_hsl();
+        Color::from_hsl(h, s, (l + amount).clamp(0.0, 1.0), a)
+    }
+
+    /// Darkens this `Color` by `amount` (`0.0..=1.0`) in HSL space, clamped to fully dark.
+    pub fn darken(self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Saturates this `Color` by `amount` (`0.0..=1.0`) in HSL space, clamped to fully saturated.
+    pub fn saturate(self, amount: f32) -> Color {
+        let (h, s, l, a) = self.to_hsl();
+        Color::from_hsl(h, (s + amount).clamp(0.0, 1.0), l, a)
+    }
+
+    /// Desaturates this `Color` by `amount` (`0.0..=1.0`) in HSL space, clamped to fully gray.
+    pub fn desaturate(self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+}
+
+/// Returns `(max_channel, chroma, hue_in_degrees)` for the given RGB triple,
+/// shared by the HSL and HSV conversions.
+fn rgb_to_hue(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * (((g - b) / delta).rem_euclid(6.0))
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (max, delta, hue)
+}
+
+/// Converts a (hue, chroma, lightness/value offset) triple, as used by both
+/// the HSV and HSL formulas, into an `(r, g, b)` triple.
+fn hsx_to_rgb(h: f32, c: f32, m: f32) -> (f32, f32, f32) {
+    let h = h.rem_euclid(360.0);
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let (r1, g1, b1) = if h < 60.0 {
+        (c, x, 0.0)
+    } else if h < 120.0 {
+        (x, c, 0.0)
+    } else if h < 180.0 {
+        (0.0, c, x)
+    } else if h < 240.0 {
+        (0.0, x, c)
+    } else if h < 300.0 {
+        (x, 0.0, c)
+    } else {
+        (c, 0.0, x)
+    };
+    (r1 + m, g1 + m, b1 + m)
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Parses a CSS-style color string, see [`Color::from_css`](struct.Color.html#method.from_css).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::from_css(s)
+    }
+}
+
+/// The standard CSS3 named colors, as `(name, 0xRRGGBB)` pairs.
+const NAMED_CSS_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
+fn named_css_color(name: &str) -> Option<(u8, u8, u8)> {
+    NAMED_CSS_COLORS.iter().find(|(n, _)| *n == name).map(|&(_, c)| {
+        (
+            ((c >> 16) & 0xFF) as u8,
+            ((c >> 8) & 0xFF) as u8,
+            (c & 0xFF) as u8,
+        )
+    })
+}
+
 /// A RGBA color in the *linear* color space,
 /// suitable for shoving into a shader.
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -335,7 +766,7 @@ impl From<LinearColor> for Color {
             if component <= 0.0031308 {
                 component * 12.92
             } else {
-                (1.0 + a) * component.powf(1.0 / 2.4)
+                (1.0 + a) * component.powf(1.0 / 2.4) - a
             }
         }
         Color {
@@ -353,6 +784,443 @@ impl From<LinearColor> for [f32; 4] {
     }
 }
 
+/// The separable blend modes and Porter-Duff compositing operators usable
+/// with `Color::blend`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Standard alpha blending: the source drawn over the backdrop.
+    SrcOver,
+    /// Additive blending; always lightens.
+    Add,
+    /// Multiplies the channels together; always darkens.
+    Multiply,
+    /// The inverse of `Multiply`; always lightens.
+    Screen,
+    /// Keeps the darker of the two channels.
+    Darken,
+    /// Keeps the lighter of the two channels.
+    Lighten,
+    /// `Multiply` or `Screen` depending on the backdrop channel.
+    Overlay,
+    /// The absolute difference between the two channels.
+    Difference,
+    /// Porter-Duff `CLEAR`: the result is fully transparent.
+    Clear,
+    /// Porter-Duff `SRC`: only the source is shown.
+    Src,
+    /// Porter-Duff `DST`: only the backdrop is shown.
+    Dst,
+    /// Porter-Duff `SRC_IN`: the source, masked by the backdrop's alpha.
+    SrcIn,
+    /// Porter-Duff `DST_OUT`: the backdrop, masked by the source's inverse alpha.
+    DstOut,
+    /// Porter-Duff `XOR`: only the non-overlapping parts of source and backdrop.
+    Xor,
+}
+
+impl Color {
+    /// Composites `self`, as the source color, over `backdrop`, using `mode`.
+    ///
+    /// The blend is performed in linear color space (via `LinearColor`) and
+    /// the result converted back to sRGB.
+    pub fn blend(self, backdrop: Color, mode: BlendMode) -> Color {
+        let src = LinearColor::from(self);
+        let dst = LinearColor::from(backdrop);
+
+        let blended = match mode {
+            BlendMode::Clear => {
+                return Color::from(LinearColor {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.0,
+                })
+            }
+            BlendMode::Src => return self,
+            BlendMode::Dst => return backdrop,
+            BlendMode::SrcIn => {
+                return Color::from(LinearColor {
+                    r: src.r,
+                    g: src.g,
+                    b: src.b,
+                    a: src.a * dst.a,
+                })
+            }
+            BlendMode::DstOut => {
+                return Color::from(LinearColor {
+                    r: dst.r,
+                    g: dst.g,
+                    b: dst.b,
+                    a: dst.a * (1.0 - src.a),
+                })
+            }
+            BlendMode::Xor => {
+                let out_a = src.a * (1.0 - dst.a) + dst.a * (1.0 - src.a);
+                return Color::from(if out_a <= 0.0 {
+                    LinearColor {
+                        r: 0.0,
+                        g: 0.0,
+                        b: 0.0,
+                        a: 0.0,
+                    }
+                } else {
+                    LinearColor {
+                        r: (src.r * src.a * (1.0 - dst.a) + dst.r * dst.a * (1.0 - src.a)) / out_a,
+                        g: (src.g * src.a * (1.0 - dst.a) + dst.g * dst.a * (1.0 - src.a)) / out_a,
+                        b: (src.b * src.a * (1.0 - dst.a) + dst.b * dst.a * (1.0 - src.a)) / out_a,
+                        a: out_a,
+                    }
+                });
+            }
+            BlendMode::SrcOver => src,
+            _ => LinearColor {
+                r: blend_channel(src.r, dst.r, mode),
+                g: blend_channel(src.g, dst.g, mode),
+                b: blend_channel(src.b, dst.b, mode),
+                a: src.a,
+            },
+        };
+
+        Color::from(source_over(&blended, &dst))
+    }
+}
+
+/// Applies one of the separable blend-mode formulas to a single channel pair.
+fn blend_channel(src: f32, dst: f32, mode: BlendMode) -> f32 {
+    match mode {
+        BlendMode::Add => (src + dst).min(1.0),
+        BlendMode::Multiply => src * dst,
+        BlendMode::Screen => src + dst - src * dst,
+        BlendMode::Darken => src.min(dst),
+        BlendMode::Lighten => src.max(dst),
+        BlendMode::Overlay => {
+            if dst <= 0.5 {
+                2.0 * src * dst
+            } else {
+                1.0 - 2.0 * (1.0 - src) * (1.0 - dst)
+            }
+        }
+        BlendMode::Difference => (src - dst).abs(),
+        _ => src,
+    }
+}
+
+/// Composites `src` over `dst` using the standard Porter-Duff "source over" formula.
+fn source_over(src: &LinearColor, dst: &LinearColor) -> LinearColor {
+    let out_a = src.a + dst.a * (1.0 - src.a);
+    if out_a <= 0.0 {
+        return LinearColor {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.0,
+        };
+    }
+    LinearColor {
+        r: (src.r * src.a + dst.r * dst.a * (1.0 - src.a)) / out_a,
+        g: (src.g * src.a + dst.g * dst.a * (1.0 - src.a)) / out_a,
+        b: (src.b * src.a + dst.b * dst.a * (1.0 - src.a)) / out_a,
+        a: out_a,
+    }
+}
+
+impl Color {
+    /// Linearly interpolates between `self` and `other` by `t`, blending in
+    /// linear color space (via `LinearColor`).
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let a = LinearColor::from(self);
+        let b = LinearColor::from(other);
+        Color::from(LinearColor {
+            r: a.r + (b.r - a.r) * t,
+            g: a.g + (b.g - a.g) * t,
+            b: a.b + (b.b - a.b) * t,
+            a: a.a + (b.a - a.a) * t,
+        })
+    }
+
+    /// Returns this `Color`'s perceived relative luminance, computed on its
+    /// linearized channels as `0.2126*r + 0.7152*g + 0.0722*b`.
+    pub fn luma(self) -> f32 {
+        let c = LinearColor::from(self);
+        0.2126 * c.r + 0.7152 * c.g + 0.0722 * c.b
+    }
+
+    /// Returns whichever of `a` or `b` has the greater luma contrast against `self`.
+    pub fn best_contrast(self, a: Color, b: Color) -> Color {
+        let luma = self.luma();
+        if (a.luma() - luma).abs() >= (b.luma() - luma).abs() {
+            a
+        } else {
+            b
+        }
+    }
+}
+
+/// A single color stop in a `Gradient`, at a normalized `offset` in `0.0..=1.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GradientStop {
+    /// Position of this stop along the gradient, in `0.0..=1.0`.
+    pub offset: f32,
+    /// The color at this stop.
+    pub color: Color,
+}
+
+/// The geometry a `Gradient` is sampled over.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum GradientGeometry {
+    Linear { start: Point2, end: Point2 },
+    Radial { center: Point2, r0: f32, r1: f32 },
+}
+
+/// A multi-stop color gradient, sampled either along a line (`linear`) or
+/// outward from a point (`radial`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    geometry: GradientGeometry,
+    stops: Vec<GradientStop>,
+}
+
+impl Gradient {
+    /// Creates a linear gradient running from `start` to `end`.
+    ///
+    /// `stops` need not be sorted by offset; they are sorted internally.
+    pub fn linear(start: Point2, end: Point2, mut stops: Vec<GradientStop>) -> Gradient {
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Gradient {
+            geometry: GradientGeometry::Linear { start, end },
+            stops,
+        }
+    }
+
+    /// Creates a radial gradient centered at `center`, from radius `r0` to `r1`.
+    ///
+    /// `stops` need not be sorted by offset; they are sorted internally.
+    pub fn radial(center: Point2, r0: f32, r1: f32, mut stops: Vec<GradientStop>) -> Gradient {
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Gradient {
+            geometry: GradientGeometry::Radial { center, r0, r1 },
+            stops,
+        }
+    }
+
+    /// Samples the gradient's color at parameter `t`, clamped to `0.0..=1.0`.
+    pub fn sample(&self, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        match self.stops.len() {
+            0 => BLACK,
+            1 => self.stops[0].color,
+            _ => {
+                let last = self.stops.len() - 1;
+                if t <= self.stops[0].offset {
+                    return self.stops[0].color;
+                }
+                if t >= self.stops[last].offset {
+                    return self.stops[last].color;
+                }
+                for pair in self.stops.windows(2) {
+                    let (a, b) = (pair[0], pair[1]);
+                    if t >= a.offset && t <= b.offset {
+                        let span = b.offset - a.offset;
+                        let local_t = if span > 0.0 { (t - a.offset) / span } else { 0.0 };
+                        return a.color.lerp(b.color, local_t);
+                    }
+                }
+                self.stops[last].color
+            }
+        }
+    }
+
+    /// Samples the gradient's color at a point in space: projecting onto the
+    /// gradient line for a linear gradient, or computing the normalized
+    /// radial distance for a radial gradient.
+    pub fn sample_at(&self, p: Point2) -> Color {
+        let t = match self.geometry {
+            GradientGeometry::Linear { start, end } => {
+                let axis = end - start;
+                let len_sq = axis.norm_squared();
+                if len_sq <= 0.0 {
+                    0.0
+                } else {
+                    (p - start).dot(&axis) / len_sq
+                }
+            }
+            GradientGeometry::Radial { center, r0, r1 } => {
+                let span = r1 - r0;
+                if span.abs() <= f32::EPSILON {
+                    0.0
+                } else {
+                    ((p - center).norm() - r0) / span
+                }
+            }
+        };
+        self.sample(t)
+    }
+}
+
+/// Describes the byte layout of pixel data, for interop with image loading
+/// and texture uploads.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ColorFormat {
+    /// 8-bit luminance (grayscale).
+    L8,
+    /// 8-bit luminance plus 8-bit alpha.
+    La8,
+    /// 8-bit red, green, blue.
+    Rgb8,
+    /// 8-bit red, green, blue, alpha.
+    Rgba8,
+    /// 16-bit red, green, blue.
+    Rgb16,
+    /// 16-bit red, green, blue, alpha.
+    Rgba16,
+    /// 32-bit float red, green, blue.
+    Rgb32F,
+    /// 32-bit float red, green, blue, alpha.
+    Rgba32F,
+}
+
+impl ColorFormat {
+    /// The number of color channels in this format.
+    pub fn channel_count(self) -> u8 {
+        match self {
+            ColorFormat::L8 => 1,
+            ColorFormat::La8 => 2,
+            ColorFormat::Rgb8 | ColorFormat::Rgb16 | ColorFormat::Rgb32F => 3,
+            ColorFormat::Rgba8 | ColorFormat::Rgba16 | ColorFormat::Rgba32F => 4,
+        }
+    }
+
+    /// Whether this format has an alpha channel.
+    pub fn has_alpha(self) -> bool {
+        match self {
+            ColorFormat::La8 | ColorFormat::Rgba8 | ColorFormat::Rgba16 | ColorFormat::Rgba32F => {
+                true
+            }
+            ColorFormat::L8 | ColorFormat::Rgb8 | ColorFormat::Rgb16 | ColorFormat::Rgb32F => false,
+        }
+    }
+
+    /// The size, in bytes, of a single pixel in this format.
+    pub fn bytes_per_pixel(self) -> u8 {
+        let channel_size = match self {
+            ColorFormat::L8 | ColorFormat::La8 | ColorFormat::Rgb8 | ColorFormat::Rgba8 => 1,
+            ColorFormat::Rgb16 | ColorFormat::Rgba16 => 2,
+            ColorFormat::Rgb32F | ColorFormat::Rgba32F => 4,
+        };
+        self.channel_count() * channel_size
+    }
+
+    /// Packs `color` into `bytes` using this format.
+    ///
+    /// Returns `None` if `bytes` is not exactly `bytes_per_pixel()` long.
+    pub fn pack(self, color: Color, bytes: &mut [u8]) -> Option<()> {
+        if bytes.len() != self.bytes_per_pixel() as usize {
+            return None;
+        }
+        match self {
+            ColorFormat::L8 => {
+                bytes[0] = luma8(color);
+            }
+            ColorFormat::La8 => {
+                let (_, _, _, a) = color.to_rgba();
+                bytes[0] = luma8(color);
+                bytes[1] = a;
+            }
+            ColorFormat::Rgb8 => {
+                let (r, g, b) = color.to_rgb();
+                bytes[0] = r;
+                bytes[1] = g;
+                bytes[2] = b;
+            }
+            ColorFormat::Rgba8 => {
+                let (r, g, b, a) = color.to_rgba();
+                bytes[0] = r;
+                bytes[1] = g;
+                bytes[2] = b;
+                bytes[3] = a;
+            }
+            ColorFormat::Rgb16 => {
+                for (i, v) in [color.r, color.g, color.b].iter().enumerate() {
+                    let bits = (v.clamp(0.0, 1.0) * 65535.0).round() as u16;
+                    bytes[i * 2..i * 2 + 2].copy_from_slice(&bits.to_le_bytes());
+                }
+            }
+            ColorFormat::Rgba16 => {
+                for (i, v) in [color.r, color.g, color.b, color.a].iter().enumerate() {
+                    let bits = (v.clamp(0.0, 1.0) * 65535.0).round() as u16;
+                    bytes[i * 2..i * 2 + 2].copy_from_slice(&bits.to_le_bytes());
+                }
+            }
+            ColorFormat::Rgb32F => {
+                for (i, v) in [color.r, color.g, color.b].iter().enumerate() {
+                    bytes[i * 4..i * 4 + 4].copy_from_slice(&v.to_le_bytes());
+                }
+            }
+            ColorFormat::Rgba32F => {
+                for (i, v) in [color.r, color.g, color.b, color.a].iter().enumerate() {
+                    bytes[i * 4..i * 4 + 4].copy_from_slice(&v.to_le_bytes());
+                }
+            }
+        }
+        Some(())
+    }
+
+    /// Unpacks a `Color` from `bytes` using this format.
+    ///
+    /// Returns `None` if `bytes` is not exactly `bytes_per_pixel()` long.
+    pub fn unpack(self, bytes: &[u8]) -> Option<Color> {
+        if bytes.len() != self.bytes_per_pixel() as usize {
+            return None;
+        }
+        fn channel16(bytes: &[u8], i: usize) -> f32 {
+            f32::from(u16::from_le_bytes([bytes[i * 2], bytes[i * 2 + 1]])) / 65535.0
+        }
+        fn channel32f(bytes: &[u8], i: usize) -> f32 {
+            f32::from_le_bytes([
+                bytes[i * 4],
+                bytes[i * 4 + 1],
+                bytes[i * 4 + 2],
+                bytes[i * 4 + 3],
+            ])
+        }
+        Some(match self {
+            ColorFormat::L8 => Color::from_rgb(bytes[0], bytes[0], bytes[0]),
+            ColorFormat::La8 => Color::from_rgba(bytes[0], bytes[0], bytes[0], bytes[1]),
+            ColorFormat::Rgb8 => Color::from_rgb(bytes[0], bytes[1], bytes[2]),
+            ColorFormat::Rgba8 => Color::from_rgba(bytes[0], bytes[1], bytes[2], bytes[3]),
+            ColorFormat::Rgb16 => Color::new(
+                channel16(bytes, 0),
+                channel16(bytes, 1),
+                channel16(bytes, 2),
+                1.0,
+            ),
+            ColorFormat::Rgba16 => Color::new(
+                channel16(bytes, 0),
+                channel16(bytes, 1),
+                channel16(bytes, 2),
+                channel16(bytes, 3),
+            ),
+            ColorFormat::Rgb32F => Color::new(
+                channel32f(bytes, 0),
+                channel32f(bytes, 1),
+                channel32f(bytes, 2),
+                1.0,
+            ),
+            ColorFormat::Rgba32F => Color::new(
+                channel32f(bytes, 0),
+                channel32f(bytes, 1),
+                channel32f(bytes, 2),
+                channel32f(bytes, 3),
+            ),
+        })
+    }
+}
+
+/// Converts `color` to a single `0-255` luminance byte, via `Color::luma()`.
+fn luma8(color: Color) -> u8 {
+    (color.luma().clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
 /// Specifies whether a shape should be drawn
 /// filled or as an outline.
 #[derive(Debug, Copy, Clone)]
@@ -430,6 +1298,273 @@ mod tests {
         assert_eq!(puce1, puce4);
     }
 
+    #[test]
+    fn test_color_from_css() {
+        assert_eq!(Color::from_css("#fff").unwrap(), Color::from_rgb(255, 255, 255));
+        assert_eq!(Color::from_css("#FF8800").unwrap(), Color::from_rgb(0xFF, 0x88, 0x00));
+        assert_eq!(
+            Color::from_css("#0008").unwrap(),
+            Color::from_rgba(0, 0, 0, 0x88)
+        );
+        assert_eq!(
+            Color::from_css("rgb(255, 128, 0)").unwrap(),
+            Color::from_rgb(255, 128, 0)
+        );
+        assert_eq!(
+            Color::from_css("rgba(255, 128, 0, 0.5)").unwrap(),
+            Color::from((255, 128, 0, 128))
+        );
+        assert_eq!(
+            Color::from_css("rgb(100%, 50%, 0%)").unwrap(),
+            Color::from_rgb(255, 128, 0)
+        );
+        assert_eq!(
+            Color::from_css("rebeccapurple").unwrap(),
+            Color::from_rgb(0x66, 0x33, 0x99)
+        );
+        assert_eq!(
+            "transparent".parse::<Color>().unwrap(),
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            Color::from_css("RGB(255, 128, 0)").unwrap(),
+            Color::from_rgb(255, 128, 0)
+        );
+        assert!(Color::from_css("not-a-color").is_err());
+        assert!(Color::from_css("#12").is_err());
+    }
+
+    #[test]
+    fn test_color_hsv_hsl_roundtrip() {
+        let red = Color::from_rgb(255, 0, 0);
+        let (h, s, v, a) = red.to_hsv();
+        assert_eq!((h, s, v, a), (0.0, 1.0, 1.0, 1.0));
+        assert_eq!(Color::from_hsv(h, s, v, a), red);
+
+        let (h, s, l, a) = red.to_hsl();
+        assert_eq!((h, s, l, a), (0.0, 1.0, 0.5, 1.0));
+        assert_eq!(Color::from_hsl(h, s, l, a), red);
+
+        let grey = Color::from_rgb(128, 128, 128);
+        let (_h, s, _l, _a) = grey.to_hsl();
+        assert_eq!(s, 0.0);
+    }
+
+    #[test]
+    fn test_color_adjusters() {
+        let red = Color::from_rgb(255, 0, 0);
+        assert_eq!(red.rotate_hue(360.0), red);
+        assert_eq!(red.lighten(1.0), WHITE);
+        assert_eq!(red.darken(1.0), BLACK);
+        assert_eq!(red.desaturate(1.0).to_hsl().1, 0.0);
+    }
+
+    #[test]
+    fn test_color_blend() {
+        let red = Color::from_rgb(255, 0, 0);
+        let blue = Color::from_rgb(0, 0, 255);
+        assert_eq!(red.blend(blue, BlendMode::Src), red);
+        assert_eq!(red.blend(blue, BlendMode::Dst), blue);
+        assert_eq!(
+            red.blend(blue, BlendMode::Clear),
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        );
+
+        let translucent_blue = Color::from_rgba(0, 0, 255, 128);
+        let half_red = Color::from_rgba(255, 0, 0, 128);
+        let blended = half_red.blend(translucent_blue, BlendMode::SrcOver);
+        assert!(blended.a > translucent_blue.a);
+
+        assert_eq!(BLACK.blend(blue, BlendMode::Multiply), BLACK);
+
+        // Separable modes, with fully opaque red-over-blue so the blended
+        // result, once composited back over the (identical) backdrop, is
+        // just the per-channel formula. Channels round-trip through linear
+        // space, so compare with an epsilon rather than bit-exact equality.
+        let assert_color_eq = |got: Color, want: Color| {
+            assert!((got.r - want.r).abs() < 1e-5, "r: {:?} vs {:?}", got, want);
+            assert!((got.g - want.g).abs() < 1e-5, "g: {:?} vs {:?}", got, want);
+            assert!((got.b - want.b).abs() < 1e-5, "b: {:?} vs {:?}", got, want);
+            assert!((got.a - want.a).abs() < 1e-5, "a: {:?} vs {:?}", got, want);
+        };
+
+        assert_color_eq(
+            red.blend(blue, BlendMode::Add),
+            Color::new(1.0, 0.0, 1.0, 1.0),
+        );
+        assert_color_eq(
+            red.blend(blue, BlendMode::Screen),
+            Color::new(1.0, 0.0, 1.0, 1.0),
+        );
+        assert_color_eq(
+            red.blend(blue, BlendMode::Darken),
+            Color::new(0.0, 0.0, 0.0, 1.0),
+        );
+        assert_color_eq(
+            red.blend(blue, BlendMode::Lighten),
+            Color::new(1.0, 0.0, 1.0, 1.0),
+        );
+        assert_color_eq(red.blend(blue, BlendMode::Overlay), blue);
+        assert_color_eq(
+            red.blend(blue, BlendMode::Difference),
+            Color::new(1.0, 0.0, 1.0, 1.0),
+        );
+
+        // Porter-Duff operators, with partial alpha so the compositing
+        // math is actually exercised.
+        let half_blue = Color::from_rgba(0, 0, 255, 128);
+        assert_color_eq(
+            red.blend(half_blue, BlendMode::SrcIn),
+            Color::new(1.0, 0.0, 0.0, half_blue.a),
+        );
+        assert_color_eq(
+            half_red.blend(blue, BlendMode::DstOut),
+            Color::new(0.0, 0.0, 1.0, 1.0 - half_red.a),
+        );
+
+        let xor = half_red.blend(half_blue, BlendMode::Xor);
+        assert!((xor.r - xor.b).abs() < 1e-5);
+        assert_eq!(xor.g, 0.0);
+        assert!(xor.a > 0.0 && xor.a < 1.0);
+    }
+
+    #[test]
+    fn test_color_lerp_luma_contrast() {
+        assert_eq!(BLACK.lerp(WHITE, 0.0), BLACK);
+        assert!((BLACK.lerp(WHITE, 1.0).r - 1.0).abs() < 1e-5);
+
+        assert_eq!(BLACK.luma(), 0.0);
+        assert!((WHITE.luma() - 1.0).abs() < 1e-5);
+        assert!(WHITE.luma() > Color::from_rgb(128, 128, 128).luma());
+
+        assert_eq!(WHITE.best_contrast(BLACK, WHITE), BLACK);
+        assert_eq!(BLACK.best_contrast(BLACK, WHITE), WHITE);
+    }
+
+    #[test]
+    fn test_gradient_linear() {
+        let gradient = Gradient::linear(
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            vec![
+                GradientStop {
+                    offset: 0.0,
+                    color: BLACK,
+                },
+                GradientStop {
+                    offset: 1.0,
+                    color: WHITE,
+                },
+            ],
+        );
+        assert_eq!(gradient.sample(0.0), BLACK);
+        assert_eq!(gradient.sample(1.0), WHITE);
+        assert_eq!(gradient.sample(-1.0), BLACK);
+        assert_eq!(gradient.sample(2.0), WHITE);
+
+        assert_eq!(gradient.sample_at(Point2::new(0.0, 0.0)), BLACK);
+        assert_eq!(gradient.sample_at(Point2::new(10.0, 0.0)), WHITE);
+    }
+
+    #[test]
+    fn test_gradient_radial() {
+        let gradient = Gradient::radial(
+            Point2::new(0.0, 0.0),
+            0.0,
+            10.0,
+            vec![
+                GradientStop {
+                    offset: 0.0,
+                    color: WHITE,
+                },
+                GradientStop {
+                    offset: 1.0,
+                    color: BLACK,
+                },
+            ],
+        );
+        assert_eq!(gradient.sample_at(Point2::new(0.0, 0.0)), WHITE);
+        assert_eq!(gradient.sample_at(Point2::new(10.0, 0.0)), BLACK);
+    }
+
+    #[test]
+    fn test_gradient_nan_offset_does_not_panic() {
+        let gradient = Gradient::linear(
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            vec![
+                GradientStop {
+                    offset: f32::NAN,
+                    color: BLACK,
+                },
+                GradientStop {
+                    offset: 0.5,
+                    color: WHITE,
+                },
+            ],
+        );
+        // Should not panic; the exact ordering of a NaN stop is unspecified.
+        let _ = gradient.sample(0.5);
+    }
+
+    #[test]
+    fn test_color_format_roundtrip() {
+        let color = Color::from_rgba(0x11, 0x22, 0x33, 0x44);
+
+        for format in &[
+            ColorFormat::Rgba8,
+            ColorFormat::Rgba16,
+            ColorFormat::Rgba32F,
+        ] {
+            assert_eq!(format.channel_count(), 4);
+            assert!(format.has_alpha());
+            let mut bytes = vec![0u8; format.bytes_per_pixel() as usize];
+            format.pack(color, &mut bytes).unwrap();
+            let unpacked = format.unpack(&bytes).unwrap();
+            assert_eq!(unpacked.to_rgba(), color.to_rgba());
+        }
+
+        let rgb_color = Color::from_rgb(0x11, 0x22, 0x33);
+        for format in &[
+            ColorFormat::Rgb8,
+            ColorFormat::Rgb16,
+            ColorFormat::Rgb32F,
+        ] {
+            assert_eq!(format.channel_count(), 3);
+            assert!(!format.has_alpha());
+            let mut bytes = vec![0u8; format.bytes_per_pixel() as usize];
+            format.pack(rgb_color, &mut bytes).unwrap();
+            let unpacked = format.unpack(&bytes).unwrap();
+            assert_eq!(unpacked.to_rgb(), rgb_color.to_rgb());
+            assert_eq!(unpacked.a, 1.0);
+        }
+
+        assert_eq!(ColorFormat::Rgb8.channel_count(), 3);
+        assert!(!ColorFormat::Rgb8.has_alpha());
+        assert_eq!(ColorFormat::L8.bytes_per_pixel(), 1);
+        assert_eq!(ColorFormat::Rgba32F.bytes_per_pixel(), 16);
+
+        let mut too_small = [0u8; 1];
+        assert!(ColorFormat::Rgba8.pack(color, &mut too_small).is_none());
+
+        // L8/La8 must mix all three channels into a real luminance value,
+        // not just forward red: pure green should not pack to black.
+        let green = Color::from_rgb(0, 255, 0);
+        let mut l8 = [0u8; 1];
+        ColorFormat::L8.pack(green, &mut l8).unwrap();
+        assert_ne!(l8[0], 0);
+        let unpacked = ColorFormat::L8.unpack(&l8).unwrap();
+        assert_eq!(unpacked.to_rgb(), (l8[0], l8[0], l8[0]));
+
+        let translucent_green = Color::from_rgba(0, 255, 0, 0x80);
+        let mut la8 = [0u8; 2];
+        ColorFormat::La8.pack(translucent_green, &mut la8).unwrap();
+        assert_eq!(la8[0], l8[0]);
+        assert_eq!(la8[1], 0x80);
+        let unpacked = ColorFormat::La8.unpack(&la8).unwrap();
+        assert_eq!(unpacked.to_rgba(), (l8[0], l8[0], l8[0], 0x80));
+    }
+
     #[test]
     fn test_rect_scaling() {
         let r1 = Rect::new(0.0, 0.0, 128.0, 128.0);
@@ -464,6 +1599,44 @@ mod tests {
         assert!(!r1.overlaps(&r2));
     }
 
+    #[test]
+    fn test_rect_combine_with() {
+        let r1 = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let r2 = Rect::new(5.0, 5.0, 10.0, 10.0);
+        assert_eq!(r1.combine_with(&r2), Rect::new(0.0, 0.0, 15.0, 15.0));
+    }
+
+    #[test]
+    fn test_rect_intersect() {
+        let r1 = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let r2 = Rect::new(5.0, 5.0, 10.0, 10.0);
+        assert_eq!(r1.intersect(&r2), Some(Rect::new(5.0, 5.0, 5.0, 5.0)));
+
+        let r3 = Rect::new(100.0, 100.0, 10.0, 10.0);
+        assert_eq!(r1.intersect(&r3), None);
+
+        // Rects that only touch along an edge agree with `overlaps`:
+        // both consider them overlapping, so `intersect` returns the
+        // zero-area `Rect` at the shared edge instead of `None`.
+        let r4 = Rect::new(10.0, 0.0, 10.0, 10.0);
+        assert!(r1.overlaps(&r4));
+        assert_eq!(r1.intersect(&r4), Some(Rect::new(10.0, 0.0, 0.0, 10.0)));
+    }
+
+    #[test]
+    fn test_rect_from_points() {
+        let points = [
+            Point2::new(1.0, 5.0),
+            Point2::new(-2.0, 2.0),
+            Point2::new(4.0, -3.0),
+        ];
+        assert_eq!(
+            Rect::from_points(&points),
+            Some(Rect::new(-2.0, -3.0, 6.0, 8.0))
+        );
+        assert_eq!(Rect::from_points(&[]), None);
+    }
+
     #[test]
     fn test_rect_transform() {
         let mut r1 = Rect::new(0.0, 0.0, 64.0, 64.0);